@@ -1,6 +1,6 @@
 use crate::commands::enterprise_deploy::deploy_enterprise;
 use crate::config::InstanceInfo;
-use crate::errors::CliError;
+use crate::errors::{CliError, ConfigError};
 use crate::metrics_sender::MetricsSender;
 use crate::output::Operation;
 use crate::project::ProjectContext;
@@ -35,6 +35,13 @@ pub async fn run(
         ));
     };
 
+    if let Err(errors) = config.db_config.validate(
+        &format!("enterprise.{instance_name}"),
+        &project.root.join("helix.toml"),
+    ) {
+        return Err(invalid_db_config_error(&instance_name, &errors).into());
+    }
+
     let op = Operation::new("Deploying", &instance_name);
     let deploy_result = deploy_enterprise(&project, &instance_name, config).await;
     let duration = start_time.elapsed().as_secs() as u32;
@@ -69,6 +76,24 @@ pub async fn run(
     }
 }
 
+/// Fold every database-setting problem into one error so they can all be fixed
+/// before the next deploy attempt.
+fn invalid_db_config_error(instance_name: &str, errors: &[ConfigError]) -> CliError {
+    let details = errors
+        .iter()
+        .map(|error| match error {
+            ConfigError::InvalidDbConfig { hint, .. } => format!("- {error} ({hint})"),
+            other => format!("- {other}"),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    CliError::new(format!(
+        "Enterprise instance '{instance_name}' has invalid database settings"
+    ))
+    .with_context(details)
+    .with_hint("fix the listed values in helix.toml, then run 'helix push' again")
+}
+
 fn resolve_instance_name(
     instance_name: Option<String>,
     project: &ProjectContext,
@@ -104,3 +129,33 @@ fn resolve_instance_name(
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn invalid_db_config_error_lists_every_problem_with_its_fix() {
+        let errors = vec![
+            ConfigError::InvalidDbConfig {
+                field: "enterprise.prod.vector_config.m".to_string(),
+                reason: "must be greater than 0".to_string(),
+                hint: "remove it to use the default (16)".to_string(),
+                path: PathBuf::from("helix.toml"),
+            },
+            ConfigError::InvalidDbConfig {
+                field: "enterprise.prod.graph_config.secondary_indices".to_string(),
+                reason: "'email' is listed more than once".to_string(),
+                hint: "remove the duplicate 'email' entry".to_string(),
+                path: PathBuf::from("helix.toml"),
+            },
+        ];
+
+        let error = invalid_db_config_error("prod", &errors);
+        let context = error.context.expect("problems are listed in the context");
+        assert_eq!(context.lines().count(), 2);
+        assert!(context.contains("vector_config.m"));
+        assert!(context.contains("(remove the duplicate 'email' entry)"));
+    }
+}
@@ -4,15 +4,18 @@ use crate::output::Operation;
 use crate::project::ProjectContext;
 use crate::prompts;
 use eyre::{Result, eyre};
+use std::time::Duration;
 
-pub async fn run(instance: Option<String>) -> Result<()> {
+pub async fn run(instance: Option<String>, timeout_secs: u64) -> Result<()> {
     let project = ProjectContext::find_and_load(None)?;
     let instance = resolve_local_instance(&project, instance)?;
     let InstanceInfo::Local(config) = project.config.get_instance(&instance)? else {
         return Err(eyre!("'{instance}' is not a local v2 instance"));
     };
     let op = Operation::new("Restarting", &instance);
-    LocalRuntime::new(&project).restart(&instance, config)?;
+    LocalRuntime::new(&project)
+        .with_stop_timeout(Duration::from_secs(timeout_secs))
+        .restart(&instance, config)?;
     op.success();
     Ok(())
 }
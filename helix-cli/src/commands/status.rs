@@ -45,15 +45,30 @@ fn print_instance(project: &ProjectContext, runtime: &LocalRuntime, name: &str)
     match project.config.get_instance(name)? {
         InstanceInfo::Local(config) => {
             let status = runtime.status(name)?;
+            let up = status
+                .as_ref()
+                .is_some_and(|status| container_is_up(&status.state));
             let state = status
                 .as_ref()
                 .map(|status| status.status.as_str())
                 .unwrap_or("not created");
+            let port = status
+                .as_ref()
+                .and_then(|status| status.host_port())
+                .unwrap_or(config.port);
+            let health = if up {
+                if runtime.query_endpoint_ready(port) {
+                    " - responding"
+                } else {
+                    " - not responding (see `helix logs`)"
+                }
+            } else {
+                ""
+            };
             print_field(
                 &format!("{name} (local)"),
                 &format!(
-                    "http://localhost:{} - {state} - storage: {}",
-                    config.port,
+                    "http://localhost:{port} - {state}{health} - storage: {}",
                     config.storage.as_str()
                 ),
             );
@@ -80,3 +95,22 @@ fn all_instances(project: &ProjectContext) -> Vec<(String, String)> {
         .map(|(name, kind)| (name.clone(), kind.to_string()))
         .collect()
 }
+
+/// `state` is the runtime's machine-readable `{{.State}}` column.
+fn container_is_up(state: &str) -> bool {
+    state == "running"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_running_containers_are_probed() {
+        assert!(container_is_up("running"));
+        assert!(!container_is_up("exited"));
+        assert!(!container_is_up("created"));
+        assert!(!container_is_up("paused"));
+        assert!(!container_is_up("restarting"));
+    }
+}
@@ -4,8 +4,9 @@ use crate::output::Operation;
 use crate::project::ProjectContext;
 use crate::prompts;
 use eyre::{Result, eyre};
+use std::time::Duration;
 
-pub async fn run(instance: Option<String>) -> Result<()> {
+pub async fn run(instance: Option<String>, timeout_secs: u64) -> Result<()> {
     let project = ProjectContext::find_and_load(None)?;
     let instance = resolve_local_instance(&project, instance)?;
     if !matches!(
@@ -15,7 +16,10 @@ pub async fn run(instance: Option<String>) -> Result<()> {
         return Err(eyre!("'{instance}' is not a local v2 instance"));
     }
     let op = Operation::new("Stopping", &instance);
-    if LocalRuntime::new(&project).stop(&instance)? {
+    if LocalRuntime::new(&project)
+        .with_stop_timeout(Duration::from_secs(timeout_secs))
+        .stop(&instance)?
+    {
         op.success();
     } else {
         crate::output::info(&format!("Instance '{instance}' was not running"));
@@ -1,6 +1,6 @@
 use crate::errors::ConfigError;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -235,6 +235,72 @@ impl Default for VectorConfig {
     }
 }
 
+impl DbConfig {
+    /// Sanity-checks the database settings of an Enterprise instance before it
+    /// is deployed. Every problem is reported, each naming the dotted field path
+    /// under `prefix` together with a suggested fix.
+    pub fn validate(&self, prefix: &str, path: &Path) -> Result<(), Vec<ConfigError>> {
+        let invalid = |field: String, reason: String, hint: String| ConfigError::InvalidDbConfig {
+            field,
+            reason,
+            hint,
+            path: path.to_path_buf(),
+        };
+        let mut errors = Vec::new();
+
+        let vector = &self.vector_config;
+        let positive = [
+            ("m", vector.m, default_m()),
+            (
+                "ef_construction",
+                vector.ef_construction,
+                default_ef_construction(),
+            ),
+            ("ef_search", vector.ef_search, default_ef_search()),
+            (
+                "db_max_size_gb",
+                vector.db_max_size_gb,
+                default_db_max_size_gb(),
+            ),
+        ];
+        for (key, value, default) in positive {
+            if value == 0 {
+                errors.push(invalid(
+                    format!("{prefix}.vector_config.{key}"),
+                    "must be greater than 0".to_string(),
+                    format!(
+                        "set it to a positive value or remove it to use the default ({default})"
+                    ),
+                ));
+            }
+        }
+
+        let field = format!("{prefix}.graph_config.secondary_indices");
+        let mut seen = HashSet::new();
+        for index in &self.graph_config.secondary_indices {
+            if index.trim().is_empty() {
+                errors.push(invalid(
+                    field.clone(),
+                    "index names cannot be empty".to_string(),
+                    "remove the empty entry or name the property to index".to_string(),
+                ));
+            } else if !seen.insert(index.trim()) {
+                errors.push(invalid(
+                    field.clone(),
+                    format!("'{index}' is listed more than once"),
+                    format!("remove the duplicate '{index}' entry"),
+                ));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
 impl Default for DbConfig {
     fn default() -> Self {
         Self {
@@ -575,6 +641,65 @@ cluster_id = ""
         ));
     }
 
+    #[test]
+    fn enterprise_db_config_is_not_checked_on_load() {
+        let config: HelixConfig = toml::from_str(
+            r#"
+[project]
+name = "demo"
+
+[enterprise.production]
+cluster_id = "cluster-123"
+
+[enterprise.production.vector_config]
+m = 0
+"#,
+        )
+        .unwrap();
+        assert!(config.validate(Path::new("helix.toml"), true).is_ok());
+    }
+
+    #[test]
+    fn db_config_validation_reports_every_problem() {
+        let path = Path::new("helix.toml");
+        assert!(
+            DbConfig::default()
+                .validate("enterprise.prod", path)
+                .is_ok()
+        );
+
+        // Previously accepted combinations stay valid.
+        let mut low_ef = DbConfig::default();
+        low_ef.vector_config.m = 32;
+        low_ef.vector_config.ef_construction = 16;
+        assert!(low_ef.validate("enterprise.prod", path).is_ok());
+
+        let mut config = DbConfig::default();
+        config.vector_config.m = 0;
+        config.vector_config.db_max_size_gb = 0;
+        config.graph_config.secondary_indices =
+            vec!["email".to_string(), " ".to_string(), " email".to_string()];
+
+        let fields: Vec<String> = config
+            .validate("enterprise.prod", path)
+            .unwrap_err()
+            .into_iter()
+            .map(|error| match error {
+                ConfigError::InvalidDbConfig { field, .. } => field,
+                other => panic!("unexpected error: {other}"),
+            })
+            .collect();
+        assert_eq!(
+            fields,
+            vec![
+                "enterprise.prod.vector_config.m",
+                "enterprise.prod.vector_config.db_max_size_gb",
+                "enterprise.prod.graph_config.secondary_indices",
+                "enterprise.prod.graph_config.secondary_indices",
+            ]
+        );
+    }
+
     #[test]
     fn local_config_can_use_disk_storage() {
         let config: HelixConfig = toml::from_str(
@@ -214,6 +214,13 @@ pub enum ConfigError {
     EmptyInstanceName { path: PathBuf },
     #[error("Enterprise instance '{name}' must have a non-empty cluster_id in {path}")]
     MissingClusterId { name: String, path: PathBuf },
+    #[error("invalid value for {field} in {path}: {reason}")]
+    InvalidDbConfig {
+        field: String,
+        reason: String,
+        hint: String,
+        path: PathBuf,
+    },
     #[error("instance '{name}' not found in helix.toml")]
     InstanceNotFound { name: String },
 }
@@ -310,6 +317,18 @@ impl ConfigError {
                 name,
                 path.display()
             )),
+            ConfigError::InvalidDbConfig {
+                field,
+                reason,
+                hint,
+                path,
+            } => CliError::new(format!(
+                "invalid value for {} in {}: {}",
+                field,
+                path.display(),
+                reason
+            ))
+            .with_hint(hint.clone()),
             ConfigError::InstanceNotFound { name } => {
                 CliError::new(format!("instance '{}' not found in helix.toml", name))
             }
@@ -17,6 +17,11 @@ pub const CONTAINER_PORT: u16 = 8080;
 const RUNTIME_START_TIMEOUT: Duration = Duration::from_secs(120);
 /// How often to re-probe the daemon while waiting for it to come up.
 const RUNTIME_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// Default for `helix stop --timeout`, in seconds.
+pub const DEFAULT_STOP_TIMEOUT_SECS: u64 = 10;
+/// How long a stopping Helix container gets to drain in-flight requests after
+/// SIGTERM before the runtime escalates to SIGKILL.
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(DEFAULT_STOP_TIMEOUT_SECS);
 const MINIO_IMAGE: &str = "minio/minio:latest";
 const MINIO_MC_IMAGE: &str = "minio/mc:latest";
 const MINIO_ACCESS_KEY: &str = "minioadmin";
@@ -29,16 +34,26 @@ const LOCAL_DB_PATH: &str = "db/";
 pub struct LocalRuntime {
     runtime: ContainerRuntime,
     project_name: String,
+    stop_grace: Duration,
 }
 
 #[derive(Debug, Clone)]
 pub struct LocalStatus {
     pub instance_name: String,
     pub container_name: String,
+    pub state: String,
     pub status: String,
     pub ports: String,
 }
 
+impl LocalStatus {
+    /// Host port the runtime published for Helix, which can differ from
+    /// helix.toml after `helix start --port` without `--persist`.
+    pub fn host_port(&self) -> Option<u16> {
+        published_host_port(&self.ports)
+    }
+}
+
 #[derive(Debug, Clone)]
 struct DiskRuntimeResources {
     minio_container: String,
@@ -51,9 +66,17 @@ impl LocalRuntime {
         Self {
             runtime: project.config.project.container_runtime,
             project_name: project.config.project.name.clone(),
+            stop_grace: STOP_GRACE_PERIOD,
         }
     }
 
+    /// Override how long a running container gets to drain after SIGTERM when
+    /// it is stopped, restarted, or replaced.
+    pub fn with_stop_timeout(mut self, grace: Duration) -> Self {
+        self.stop_grace = grace;
+        self
+    }
+
     pub fn check_available(runtime: ContainerRuntime) -> Result<()> {
         let output = match Command::new(runtime.binary()).arg("info").output() {
             Ok(output) => output,
@@ -201,6 +224,8 @@ impl LocalRuntime {
 
         let name = self.container_name(instance_name);
         let image = config.image_ref();
+        // Let an already-running instance drain before it is replaced.
+        let _ = self.stop_container(&name, self.stop_grace);
         let _ = self.remove_container(&name);
         let disk_resources = if config.storage.is_disk() {
             Some(self.start_disk_dependencies(instance_name)?)
@@ -234,6 +259,8 @@ impl LocalRuntime {
 
         let name = self.container_name(instance_name);
         let image = config.image_ref();
+        // Let an already-running instance drain before it is replaced.
+        let _ = self.stop_container(&name, self.stop_grace);
         let _ = self.remove_container(&name);
         let disk_resources = if config.storage.is_disk() {
             Some(self.start_disk_dependencies(instance_name)?)
@@ -265,6 +292,17 @@ impl LocalRuntime {
             signal = tokio::signal::ctrl_c() => {
                 signal?;
                 crate::output::info("Stopping foreground local Helix instance");
+                // Stop asynchronously so a second Ctrl-C can skip the drain and
+                // fall through to the force-remove below.
+                let graceful_stop = TokioCommand::new(self.runtime.binary())
+                    .args(stop_container_args(&name, self.stop_grace))
+                    .output();
+                tokio::select! {
+                    _ = graceful_stop => {}
+                    _ = tokio::signal::ctrl_c() => {
+                        crate::output::info("Force-stopping local Helix instance");
+                    }
+                }
                 let _ = self.remove_container(&name);
                 if config.storage.is_disk() {
                     let _ = self.remove_disk_resources(instance_name, false);
@@ -286,6 +324,12 @@ impl LocalRuntime {
 
     pub fn stop(&self, instance_name: &str) -> Result<bool> {
         let name = self.container_name(instance_name);
+        // Stop before removing so Helix gets SIGTERM and can finish in-flight
+        // writes; `rm -f` alone would SIGKILL it mid-request. A container the
+        // runtime refuses to stop is still force-removed below.
+        if let Err(error) = self.stop_container(&name, self.stop_grace) {
+            crate::output::warning(&format!("{error}\nForce-removing {name}"));
+        }
         let removed_helix = self.remove_container(&name)?;
         let removed_disk_resources = self.remove_disk_resources(instance_name, false)?;
         Ok(removed_helix || removed_disk_resources)
@@ -298,7 +342,7 @@ impl LocalRuntime {
 
         let name = self.container_name(instance_name);
         let output = Command::new(self.runtime.binary())
-            .args(["restart", &name])
+            .args(restart_container_args(&name, self.stop_grace))
             .output()
             .map_err(|e| eyre!("Failed to restart {name}: {e}"))?;
 
@@ -341,7 +385,7 @@ impl LocalRuntime {
                 "ps",
                 "-a",
                 "--format",
-                "{{.Names}}\t{{.Status}}\t{{.Ports}}",
+                "{{.Names}}\t{{.State}}\t{{.Status}}\t{{.Ports}}",
                 "--filter",
                 &format!("name=^{name}$"),
             ])
@@ -358,15 +402,16 @@ impl LocalRuntime {
             return Ok(None);
         };
         let parts: Vec<&str> = line.split('\t').collect();
-        if parts.len() < 3 {
+        if parts.len() < 4 {
             return Ok(None);
         }
 
         Ok(Some(LocalStatus {
             instance_name: instance_name.to_string(),
             container_name: parts[0].to_string(),
-            status: parts[1].to_string(),
-            ports: parts[2].to_string(),
+            state: parts[1].to_string(),
+            status: parts[2].to_string(),
+            ports: parts[3].to_string(),
         }))
     }
 
@@ -538,6 +583,25 @@ impl LocalRuntime {
             .unwrap_or(false)
     }
 
+    /// Gracefully stop a container: SIGTERM, then SIGKILL once `grace` elapses.
+    /// Returns `false` if the container does not exist.
+    fn stop_container(&self, name: &str, grace: Duration) -> Result<bool> {
+        let output = Command::new(self.runtime.binary())
+            .args(stop_container_args(name, grace))
+            .output()
+            .map_err(|e| eyre!("Failed to stop {name}: {e}"))?;
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if missing_resource(&stderr) {
+            return Ok(false);
+        }
+
+        if !output.status.success() {
+            return Err(eyre!("Failed to stop {name}:\n{stderr}"));
+        }
+        Ok(true)
+    }
+
     fn remove_container(&self, name: &str) -> Result<bool> {
         let output = Command::new(self.runtime.binary())
             .args(["rm", "-f", name])
@@ -571,7 +635,8 @@ impl LocalRuntime {
             .into())
     }
 
-    fn query_endpoint_ready(&self, port: u16) -> bool {
+    /// Returns whether the instance on `port` answers a trivial read query.
+    pub fn query_endpoint_ready(&self, port: u16) -> bool {
         let Ok(mut stream) = TcpStream::connect_timeout(
             &(std::net::Ipv4Addr::LOCALHOST, port).into(),
             Duration::from_millis(500),
@@ -723,6 +788,37 @@ fn helix_run_args(
     args
 }
 
+/// Find the host side of the `<host>:<port>-><CONTAINER_PORT>/tcp` mapping in
+/// a runtime `ps` ports column such as `0.0.0.0:9000->8080/tcp, :::9000->8080/tcp`.
+fn published_host_port(ports: &str) -> Option<u16> {
+    let container = format!("{CONTAINER_PORT}/tcp");
+    ports.split(',').find_map(|mapping| {
+        let (host, target) = mapping.trim().split_once("->")?;
+        if target != container {
+            return None;
+        }
+        host.rsplit(':').next()?.parse().ok()
+    })
+}
+
+fn stop_container_args(name: &str, grace: Duration) -> Vec<String> {
+    vec![
+        "stop".to_string(),
+        "--time".to_string(),
+        grace.as_secs().to_string(),
+        name.to_string(),
+    ]
+}
+
+fn restart_container_args(name: &str, grace: Duration) -> Vec<String> {
+    vec![
+        "restart".to_string(),
+        "--time".to_string(),
+        grace.as_secs().to_string(),
+        name.to_string(),
+    ]
+}
+
 fn minio_run_args(resources: &DiskRuntimeResources) -> Vec<String> {
     vec![
         "run".to_string(),
@@ -861,6 +957,35 @@ mod tests {
         assert!(args.contains(&"AWS_ALLOW_HTTP=true".to_string()));
     }
 
+    #[test]
+    fn stop_args_send_sigterm_with_grace_period() {
+        let args = stop_container_args("helix-demo-dev", Duration::from_secs(10));
+
+        assert_eq!(args, vec!["stop", "--time", "10", "helix-demo-dev"]);
+    }
+
+    #[test]
+    fn restart_args_use_grace_period() {
+        let args = restart_container_args("helix-demo-dev", Duration::from_secs(30));
+
+        assert_eq!(args, vec!["restart", "--time", "30", "helix-demo-dev"]);
+    }
+
+    #[test]
+    fn published_host_port_reads_helix_mapping() {
+        assert_eq!(
+            published_host_port("0.0.0.0:9000->8080/tcp, :::9000->8080/tcp"),
+            Some(9000)
+        );
+        assert_eq!(
+            published_host_port("0.0.0.0:9999->9000/tcp, 0.0.0.0:6969->8080/tcp"),
+            Some(6969)
+        );
+        assert_eq!(published_host_port("[::]:7000->8080/tcp"), Some(7000));
+        assert_eq!(published_host_port(""), None);
+        assert_eq!(published_host_port("8080/tcp"), None);
+    }
+
     #[test]
     fn minio_args_include_persistent_volume() {
         let resources = disk_resources();
@@ -4,8 +4,8 @@ use color_eyre::owo_colors::OwoColorize;
 use eyre::Result;
 use helix_cli::{
     AddTarget, AuthAction, ClusterConfigAction, ConfigAction, InitTarget, MetricsAction,
-    ProjectConfigAction, SkillsAction, WorkspaceConfigAction, commands, errors, metrics_sender,
-    output, update,
+    ProjectConfigAction, SkillsAction, WorkspaceConfigAction, commands, errors, local_runtime,
+    metrics_sender, output, update,
 };
 use std::io::IsTerminal;
 use tui_banner::{Align, Banner, ColorMode, Fill, Gradient, Palette};
@@ -114,12 +114,18 @@ enum Commands {
     Stop {
         /// Instance name to stop
         instance: Option<String>,
+        /// Seconds to let in-flight requests finish before the instance is killed
+        #[arg(long, value_name = "SECS", default_value_t = local_runtime::DEFAULT_STOP_TIMEOUT_SECS)]
+        timeout: u64,
     },
 
     /// Restart a background local v2 instance
     Restart {
         /// Instance name to restart
         instance: Option<String>,
+        /// Seconds to let in-flight requests finish before the instance is killed
+        #[arg(long, value_name = "SECS", default_value_t = local_runtime::DEFAULT_STOP_TIMEOUT_SECS)]
+        timeout: u64,
     },
 
     /// Show local and Enterprise Cloud instance status
@@ -673,8 +679,10 @@ async fn main() -> Result<()> {
             disk,
             persist,
         }) => commands::start::run(instance, foreground, port, disk, persist).await,
-        Some(Commands::Stop { instance }) => commands::stop::run(instance).await,
-        Some(Commands::Restart { instance }) => commands::restart::run(instance).await,
+        Some(Commands::Stop { instance, timeout }) => commands::stop::run(instance, timeout).await,
+        Some(Commands::Restart { instance, timeout }) => {
+            commands::restart::run(instance, timeout).await
+        }
         Some(Commands::Status { instance }) => commands::status::run(instance).await,
         Some(Commands::Logs {
             instance,
@@ -823,6 +831,38 @@ mod tests {
         assert!(Cli::try_parse_from(["helix", "start", "qa", "--foreground", "--detach"]).is_err());
     }
 
+    #[test]
+    fn stop_timeout_defaults_and_overrides() {
+        let cli = Cli::parse_from(["helix", "stop", "qa"]);
+        match cli.command {
+            Some(Commands::Stop { timeout, .. }) => {
+                assert_eq!(timeout, local_runtime::DEFAULT_STOP_TIMEOUT_SECS);
+            }
+            _ => panic!("expected stop command"),
+        }
+
+        let cli = Cli::parse_from(["helix", "stop", "qa", "--timeout", "60"]);
+        match cli.command {
+            Some(Commands::Stop { instance, timeout }) => {
+                assert_eq!(instance.as_deref(), Some("qa"));
+                assert_eq!(timeout, 60);
+            }
+            _ => panic!("expected stop command"),
+        }
+    }
+
+    #[test]
+    fn restart_accepts_stop_timeout() {
+        let cli = Cli::parse_from(["helix", "restart", "qa", "--timeout", "45"]);
+        match cli.command {
+            Some(Commands::Restart { instance, timeout }) => {
+                assert_eq!(instance.as_deref(), Some("qa"));
+                assert_eq!(timeout, 45);
+            }
+            _ => panic!("expected restart command"),
+        }
+    }
+
     #[test]
     fn init_local_disk_flag_parses() {
         let cli = Cli::parse_from(["helix", "init", "local", "--disk"]);
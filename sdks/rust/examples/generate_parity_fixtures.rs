@@ -104,6 +104,7 @@ fn with_params(
     request
 }
 
+#[allow(clippy::too_many_arguments)]
 fn user_props(
     external_id: &'static str,
     name: &'static str,
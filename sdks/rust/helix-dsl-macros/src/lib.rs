@@ -294,6 +294,8 @@ fn parse_param_type(ty: &Type) -> syn::Result<ParamTypeSpec> {
         }
         "Vec" => {
             let inner = single_type_arg(segment, ty)?;
+            // Nested rather than a let chain so the crate builds below Rust 1.88.
+            #[allow(clippy::collapsible_if)]
             if let Type::Path(inner_path) = inner {
                 if let Some(inner_seg) = inner_path.path.segments.last() {
                     if inner_seg.ident == "u8" && matches!(inner_seg.arguments, PathArguments::None)
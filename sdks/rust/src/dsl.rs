@@ -570,7 +570,31 @@
 //!     .var_as("edge_ids", g().e(EdgeRef::var("edge_ops")).id())
 //!     .var_as("edge_labels", g().e(EdgeRef::var("edge_ops")).label())
 //!     .var_as("edge_props", g().e(EdgeRef::var("edge_ops")).edge_properties())
-//!     .returning(["edge_ops", "edge_props"]);
+//!     .var_as(
+//!         "edges_ranked",
+//!         g()
+//!             .e(EdgeRef::var("edge_ops"))
+//!             .order_by_multiple(vec![("weight", Order::Desc), ("since", Order::Asc)]),
+//!     )
+//!     .var_as("edge_groups", g().e(EdgeRef::var("edge_ops")).group("kind"))
+//!     .var_as(
+//!         "edge_group_counts",
+//!         g().e(EdgeRef::var("edge_ops")).group_count("kind"),
+//!     )
+//!     .var_as(
+//!         "edge_weight_mean",
+//!         g()
+//!             .e(EdgeRef::var("edge_ops"))
+//!             .aggregate_by(AggregateFunction::Mean, "weight"),
+//!     )
+//!     .returning([
+//!         "edge_ops",
+//!         "edge_props",
+//!         "edges_ranked",
+//!         "edge_groups",
+//!         "edge_group_counts",
+//!         "edge_weight_mean",
+//!     ]);
 //! ```
 //!
 //! ### Branching, Sub-Traversals, Repeat, Grouping, Paths, and Sack
@@ -1421,6 +1445,7 @@ pub enum Expr {
     },
 }
 
+#[allow(clippy::should_implement_trait)]
 impl Expr {
     /// Create a property reference expression
     pub fn prop(name: impl Into<String>) -> Self {
@@ -1809,6 +1834,7 @@ impl From<SourcePredicate> for Predicate {
     }
 }
 
+#[allow(clippy::should_implement_trait)]
 impl Predicate {
     /// Create an equality predicate.
     ///
@@ -2193,43 +2219,34 @@ fn validate_binding_name(name: impl Into<String>) -> String {
 }
 
 /// Sort order for ordering steps
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Order {
     /// Ascending order (smallest first)
+    #[default]
     Asc,
     /// Descending order (largest first)
     Desc,
 }
 
-impl Default for Order {
-    fn default() -> Self {
-        Order::Asc
-    }
-}
-
 /// Physical ordering for range-index storage.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum RangeIndexDirection {
     /// Store values in ascending order.
+    #[default]
     Asc,
     /// Store values in descending order.
     Desc,
 }
 
-impl Default for RangeIndexDirection {
-    fn default() -> Self {
-        RangeIndexDirection::Asc
-    }
-}
-
 fn is_default_range_index_direction(direction: &RangeIndexDirection) -> bool {
     *direction == RangeIndexDirection::Asc
 }
 
 /// Emit behavior for repeat steps
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum EmitBehavior {
     /// Don't emit intermediate results.
+    #[default]
     None,
     /// Emit the current node stream before each repeat iteration.
     Before,
@@ -2239,12 +2256,6 @@ pub enum EmitBehavior {
     All,
 }
 
-impl Default for EmitBehavior {
-    fn default() -> Self {
-        EmitBehavior::None
-    }
-}
-
 /// Aggregation function for reduce operations
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AggregateFunction {
@@ -4401,6 +4412,27 @@ impl<M: MutationMode> Traversal<OnEdges, M> {
         self.push_step(Step::EdgeProperties)
     }
 
+    // Aggregation Steps: OnEdges -> Terminal
+
+    /// Group edges by a property value.
+    pub fn group(self, property: impl Into<String>) -> Traversal<Terminal, M> {
+        self.push_step(Step::Group(property.into()))
+    }
+
+    /// Count edge occurrences grouped by a property.
+    pub fn group_count(self, property: impl Into<String>) -> Traversal<Terminal, M> {
+        self.push_step(Step::GroupCount(property.into()))
+    }
+
+    /// Apply an aggregation function to an edge property.
+    pub fn aggregate_by(
+        self,
+        function: AggregateFunction,
+        property: impl Into<String>,
+    ) -> Traversal<Terminal, M> {
+        self.push_step(Step::AggregateBy(function, property.into()))
+    }
+
     // Ordering Steps: OnEdges -> OnEdges
 
     /// Order results by a property.
@@ -4410,6 +4442,18 @@ impl<M: MutationMode> Traversal<OnEdges, M> {
     pub fn order_by(self, property: impl Into<String>, order: Order) -> Self {
         self.push_step(Step::OrderBy(property.into(), order))
     }
+
+    /// Order results by multiple properties with priorities.
+    ///
+    /// Earlier entries take precedence; later entries only break ties.
+    ///
+    /// Note: some interpreters represent intermediate streams as sets. In those
+    /// engines, ordering may not be preserved in the returned edge set.
+    pub fn order_by_multiple(self, orderings: Vec<(impl Into<String>, Order)>) -> Self {
+        let orderings: Vec<(String, Order)> =
+            orderings.into_iter().map(|(p, o)| (p.into(), o)).collect();
+        self.push_step(Step::OrderByMultiple(orderings))
+    }
 }
 
 // Terminal State - No additional methods (traversal is complete)
@@ -5062,8 +5106,10 @@ mod tests {
 
     #[test]
     fn query_bundle_rejects_unsupported_version() {
-        let mut bundle = QueryBundle::default();
-        bundle.version = QUERY_BUNDLE_VERSION + 1;
+        let bundle = QueryBundle {
+            version: QUERY_BUNDLE_VERSION + 1,
+            ..QueryBundle::default()
+        };
 
         let bytes = serialize_query_bundle(&bundle).expect("serialize query bundle");
         let err = deserialize_query_bundle(&bytes).expect_err("version should fail");
@@ -5079,8 +5125,10 @@ mod tests {
 
     #[test]
     fn query_bundle_accepts_legacy_v4_version() {
-        let mut bundle = QueryBundle::default();
-        bundle.version = LEGACY_QUERY_BUNDLE_VERSION_V4;
+        let bundle = QueryBundle {
+            version: LEGACY_QUERY_BUNDLE_VERSION_V4,
+            ..QueryBundle::default()
+        };
 
         let bytes = serialize_query_bundle(&bundle).expect("serialize query bundle");
         let decoded = deserialize_query_bundle(&bytes).expect("legacy version should deserialize");
@@ -5215,6 +5263,45 @@ mod tests {
         assert_eq!(t.steps.len(), 5);
     }
 
+    #[test]
+    fn test_edge_order_by_multiple() {
+        let t = g()
+            .e_with_label("FOLLOWS")
+            .order_by_multiple(vec![("weight", Order::Desc), ("since", Order::Asc)])
+            .limit(10);
+
+        assert!(matches!(
+            &t.steps[1],
+            Step::OrderByMultiple(orderings)
+                if orderings
+                    == &vec![
+                        ("weight".to_string(), Order::Desc),
+                        ("since".to_string(), Order::Asc),
+                    ]
+        ));
+        assert_eq!(
+            sonic_rs::to_string(&t.steps[1]).unwrap(),
+            r#"{"OrderByMultiple":[["weight","Desc"],["since","Asc"]]}"#
+        );
+    }
+
+    #[test]
+    fn test_edge_aggregations() {
+        let grouped = g().e_with_label("FOLLOWS").group("kind");
+        assert!(matches!(&grouped.steps[1], Step::Group(p) if p == "kind"));
+
+        let counted = g().e_with_label("FOLLOWS").group_count("kind");
+        assert!(matches!(&counted.steps[1], Step::GroupCount(p) if p == "kind"));
+
+        let mean = g()
+            .e_with_label("FOLLOWS")
+            .aggregate_by(AggregateFunction::Mean, "weight");
+        assert_eq!(
+            sonic_rs::to_string(&mean.steps[1]).unwrap(),
+            r#"{"AggregateBy":["Mean","weight"]}"#
+        );
+    }
+
     #[test]
     fn test_sub_traversal() {
         let t = g()
@@ -77,6 +77,7 @@ pub mod dsl;
 pub mod query_generator;
 
 use std::marker::PhantomData;
+use std::time::Duration;
 
 // Re-export the DSL surface (types, builders, `prelude`, etc.) at the crate
 // root. This is also what makes the `crate::*` paths used inside `dsl.rs` and
@@ -87,6 +88,7 @@ pub use dsl::*;
 // addition to the canonical `helix_db::dsl::prelude::*`.
 pub use dsl::prelude;
 
+use reqwest::header::{HeaderMap, RETRY_AFTER};
 use reqwest::{Client as ReqwestClient, StatusCode};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -95,8 +97,9 @@ use thiserror::Error;
 ///
 /// A thin async wrapper over [`reqwest`] that knows how to reach a Helix
 /// gateway's query routes. Construct it with [`Client::new`], optionally attach
-/// a bearer API key via [`Client::with_api_key`], then build and send requests
-/// through [`Client::query`].
+/// a bearer API key via [`Client::with_api_key`], a request timeout via
+/// [`Client::with_timeout`], and a retry budget via [`Client::with_retries`],
+/// then build and send requests through [`Client::query`].
 ///
 /// The client is cheap to [`Clone`] — the underlying `reqwest::Client` shares
 /// its connection pool — so a single instance can be reused across tasks.
@@ -114,7 +117,9 @@ use thiserror::Error;
 ///
 /// // Or point at a remote cluster and attach an API key.
 /// let remote = Client::new(Some("https://cluster.helix-db.com"))?
-///     .with_api_key(Some("hx_your_api_key"));
+///     .with_api_key(Some("hx_your_api_key"))
+///     .with_timeout(std::time::Duration::from_secs(30))
+///     .with_retries(3);
 /// # let _ = (local, remote);
 /// # Ok(())
 /// # }
@@ -124,6 +129,8 @@ pub struct Client {
     client: ReqwestClient,
     url: reqwest::Url,
     api_key: Option<String>,
+    timeout: Option<Duration>,
+    max_retries: u32,
 }
 
 /// Backwards-compatible alias for [`Client`].
@@ -139,8 +146,11 @@ pub enum HelixError {
     /// The server responded with a non-`200` status. `details` carries the
     /// response body, or the status' canonical reason phrase when no body is
     /// available.
-    #[error("Got Error from server: {details}")]
+    #[error("Got Error from server ({status}): {details}")]
     RemoteError {
+        /// HTTP status of the response, e.g. to tell rate limiting (`429`)
+        /// apart from server failures (`5xx`).
+        status: StatusCode,
         /// Server-provided error text, or a fallback description of the status.
         details: String,
     },
@@ -176,6 +186,8 @@ impl Client {
             client: ReqwestClient::new(),
             url,
             api_key: None,
+            timeout: None,
+            max_retries: 0,
         })
     }
 
@@ -188,6 +200,29 @@ impl Client {
         self
     }
 
+    /// Bound how long each request attempt may take.
+    ///
+    /// The timeout covers connecting, sending, and reading the response. When
+    /// it elapses, [`QueryRequest::send`] returns [`HelixError::ReqwestError`]
+    /// (check [`reqwest::Error::is_timeout`]). Requests have no timeout by
+    /// default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry requests the server turned away, up to `max_retries` extra attempts.
+    ///
+    /// Only failures where the query cannot have run are retried: connection
+    /// errors and `429 Too Many Requests` / `503 Service Unavailable`
+    /// responses. Retries wait for the server's `Retry-After` (in seconds) when
+    /// present, otherwise back off exponentially from 100ms; either way a single
+    /// wait never exceeds 6.4s. Defaults to `0` (no retries).
+    pub fn with_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
     /// Start building a request.
     ///
     /// `R` is the type the JSON response body is deserialized into by
@@ -307,7 +342,6 @@ impl<'hlx, 'a, R> QueryBuilder<'hlx, 'a, R> {
     ///
     /// Returns [`HelixError::SerializationError`] if `data` cannot be serialized
     /// to JSON.
-    #[must_use]
     pub fn body<T: Serialize + Sync>(mut self, data: &T) -> Result<Self, HelixError> {
         self.body = Some(sonic_rs::to_vec(data)?);
         Ok(self)
@@ -348,13 +382,14 @@ impl<'hlx, 'a, R: for<'de> Deserialize<'de>> QueryRequest<'hlx, 'a, R> {
     ///
     /// Resolves the route (`/v1/query` for dynamic, `/v1/query/<name>` for
     /// stored), applies the toggled headers and bearer API key, attaches the
-    /// body, and awaits the response.
+    /// body, and awaits the response. Honors the client's
+    /// [timeout](Client::with_timeout) and [retry budget](Client::with_retries).
     ///
     /// # Errors
     ///
-    /// - [`HelixError::ReqwestError`] for transport failures.
+    /// - [`HelixError::ReqwestError`] for transport failures, including timeouts.
     /// - [`HelixError::RemoteError`] for any non-`200` response (carrying the
-    ///   server's body or status reason).
+    ///   status and the server's body or status reason).
     /// - [`HelixError::SerializationError`] if the request payload cannot be
     ///   serialized or the response body cannot be deserialized into `R`.
     ///
@@ -383,25 +418,47 @@ impl<'hlx, 'a, R: for<'de> Deserialize<'de>> QueryRequest<'hlx, 'a, R> {
                 unreachable!("send() is only reachable after stored() or dynamic() sets query_type")
             }
         };
-        let url = query_request
-            .client
+        let client = query_request.client;
+        let url = client
             .url
             .join(&url)
             .map_err(|e| HelixError::InvalidURL(e.to_string()))?;
 
-        let mut request = query_request.client.client.post(url);
-
+        let mut request = client.client.post(url);
         for (k, v) in query_request.headers.into_iter().flatten() {
             request = request.header(k, v);
         }
-        if let Some(ref api_key) = query_request.client.api_key {
+        if let Some(ref api_key) = client.api_key {
             request = request.bearer_auth(api_key);
         }
+        if let Some(timeout) = client.timeout {
+            request = request.timeout(timeout);
+        }
         if let Some(body) = body {
             request = request.body(body);
         }
 
-        let response = request.send().await?;
+        let mut attempt = 0;
+        let response = loop {
+            // Only keep a copy when another attempt is allowed. The body is
+            // reference-counted, so cloning the request does not copy it.
+            let retry = if attempt < client.max_retries {
+                request.try_clone()
+            } else {
+                None
+            };
+            let delay = match request.send().await {
+                Ok(response) if retry.is_some() && is_retryable_status(response.status()) => {
+                    retry_after(response.headers()).unwrap_or_else(|| retry_backoff(attempt))
+                }
+                Ok(response) => break response,
+                Err(e) if retry.is_some() && e.is_connect() => retry_backoff(attempt),
+                Err(e) => return Err(e.into()),
+            };
+            request = retry.expect("retries are only scheduled with a cloned request");
+            attempt += 1;
+            tokio::time::sleep(delay).await;
+        };
 
         match response.status() {
             StatusCode::OK => {
@@ -409,12 +466,17 @@ impl<'hlx, 'a, R: for<'de> Deserialize<'de>> QueryRequest<'hlx, 'a, R> {
                 sonic_rs::from_slice::<R>(&bytes).map_err(Into::into)
             }
             code => match response.text().await {
-                Ok(t) => Err(HelixError::RemoteError { details: t }),
+                Ok(t) => Err(HelixError::RemoteError {
+                    status: code,
+                    details: t,
+                }),
                 Err(_) => match code.canonical_reason() {
                     Some(r) => Err(HelixError::RemoteError {
+                        status: code,
                         details: r.to_string(),
                     }),
                     None => Err(HelixError::RemoteError {
+                        status: code,
                         details: format!("unkown error with code: {code}"),
                     }),
                 },
@@ -423,6 +485,36 @@ impl<'hlx, 'a, R: for<'de> Deserialize<'de>> QueryRequest<'hlx, 'a, R> {
     }
 }
 
+/// Statuses where the server rejected the request before running the query.
+fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+    )
+}
+
+/// Longest single wait between attempts, whatever the server asks for.
+const MAX_RETRY_DELAY: Duration = Duration::from_millis(6400);
+
+/// Parse a `Retry-After` header given in seconds, capped at [`MAX_RETRY_DELAY`].
+/// HTTP-date values are ignored and fall back to [`retry_backoff`].
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_DELAY))
+}
+
+/// Exponential backoff for the given (zero-based) retry attempt, capped at
+/// [`MAX_RETRY_DELAY`].
+fn retry_backoff(attempt: u32) -> Duration {
+    Duration::from_millis(100 << attempt.min(6)).min(MAX_RETRY_DELAY)
+}
+
 extern crate self as helix_db;
 
 #[cfg(test)]
@@ -934,6 +1026,54 @@ mod client_tests {
         assert!(cleared.api_key.is_none());
     }
 
+    #[test]
+    fn timeout_and_retries_default_off() {
+        let client = Client::new(None).unwrap();
+        assert!(client.timeout.is_none());
+        assert_eq!(client.max_retries, 0);
+
+        let client = client.with_timeout(Duration::from_secs(5)).with_retries(3);
+        assert_eq!(client.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(client.max_retries, 3);
+    }
+
+    // ---- Retry policy -------------------------------------------------------
+
+    #[test]
+    fn only_rejected_statuses_are_retried() {
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(!is_retryable_status(StatusCode::GATEWAY_TIMEOUT));
+        assert!(!is_retryable_status(StatusCode::BAD_REQUEST));
+    }
+
+    #[test]
+    fn retry_after_parses_capped_seconds_only() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "2".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(2)));
+
+        headers.insert(RETRY_AFTER, "86400".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(MAX_RETRY_DELAY));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn retry_backoff_doubles_and_caps() {
+        assert_eq!(retry_backoff(0), Duration::from_millis(100));
+        assert_eq!(retry_backoff(1), Duration::from_millis(200));
+        assert_eq!(retry_backoff(6), Duration::from_millis(6400));
+        assert_eq!(retry_backoff(20), Duration::from_millis(6400));
+    }
+
     // ---- Header assembly ----------------------------------------------------
 
     #[test]
@@ -1054,4 +1194,56 @@ mod client_tests {
             .unwrap();
         assert_eq!(handle.await.unwrap(), "/v1/query/add_user");
     }
+
+    /// Spawn an HTTP server that answers successive connections with the given
+    /// raw responses. The handle resolves to the number of requests served.
+    async fn spawn_scripted_server(
+        responses: Vec<&'static str>,
+    ) -> (String, tokio::task::JoinHandle<usize>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            for resp in &responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await.unwrap();
+                socket.write_all(resp.as_bytes()).await.unwrap();
+            }
+            responses.len()
+        });
+        (base, handle)
+    }
+
+    const UNAVAILABLE: &str = "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\nContent-Length: 4\r\nConnection: close\r\n\r\nbusy";
+    const OK: &str = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}";
+
+    #[tokio::test]
+    async fn send_retries_unavailable_responses() {
+        let (base, handle) = spawn_scripted_server(vec![UNAVAILABLE, UNAVAILABLE, OK]).await;
+        let client = Client::new(Some(&base)).unwrap().with_retries(2);
+        let _: EmptyResp = client
+            .query()
+            .dynamic(sample_request())
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(handle.await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn send_returns_remote_error_once_retries_are_exhausted() {
+        let (base, handle) = spawn_scripted_server(vec![UNAVAILABLE, UNAVAILABLE]).await;
+        let client = Client::new(Some(&base)).unwrap().with_retries(1);
+        let result = client
+            .query::<EmptyResp>()
+            .dynamic(sample_request())
+            .send()
+            .await;
+        assert!(
+            matches!(result, Err(HelixError::RemoteError { status, details })
+                if status == StatusCode::SERVICE_UNAVAILABLE && details == "busy")
+        );
+        assert_eq!(handle.await.unwrap(), 2);
+    }
 }